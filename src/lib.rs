@@ -21,7 +21,7 @@
 #![no_std]
 
 use core::convert::{From, Into};
-use core::ops::{Add, Sub, Not, Mul, Div, Shr, Shl};
+use core::ops::{Add, Sub, Not, Mul, Div, Rem, Shr, Shl};
 use core::cmp::Ordering;
 
 #[repr(C)]
@@ -36,21 +36,6 @@ impl U256 {
         arr[0] as u32
     }
 
-    pub fn mul_u32(self, other: u32) -> U256 {
-        let U256(ref arr) = self;
-        let mut carry = [0u64; 4];
-        let mut ret = [0u64; 4];
-        for i in 0..4 {
-            let upper = other as u64 * (arr[i] >> 32);
-            let lower = other as u64 * (arr[i] & 0xFFFFFFFF);
-            if i < 3 {
-                carry[i + 1] += upper >> 32;
-            }
-            ret[i] = lower + (upper << 32);
-        }
-        U256(ret) + U256(carry)
-    }
-
     pub fn bits(&self) -> usize {
         let &U256(ref arr) = self;
         for i in 1..4 {
@@ -107,6 +92,107 @@ impl<'a> From<&'a [u8]> for U256 {
     }
 }
 
+/// An error encountered while parsing a `U256` from a hex string.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum ParseError {
+    /// The string held more hex digits than fit into 256 bits.
+    InvalidLength,
+    /// The string contained a byte that isn't a hex digit.
+    InvalidCharacter,
+}
+
+impl U256 {
+    /// Converts to big-endian (network) byte order.
+    pub fn to_big_endian(&self) -> [u8; 32] {
+        let &U256(ref arr) = self;
+        let mut ret = [0u8; 32];
+        for i in 0..4 {
+            let offset = i * 8;
+            ret[offset..offset + 8].copy_from_slice(&arr[3 - i].to_be_bytes());
+        }
+        ret
+    }
+
+    /// Converts to little-endian byte order.
+    pub fn to_little_endian(&self) -> [u8; 32] {
+        let &U256(ref arr) = self;
+        let mut ret = [0u8; 32];
+        for i in 0..4 {
+            let offset = i * 8;
+            ret[offset..offset + 8].copy_from_slice(&arr[i].to_le_bytes());
+        }
+        ret
+    }
+
+    /// Parses a fixed-width big-endian (network) byte order encoding.
+    ///
+    /// Accepts slices shorter than 32 bytes, treating missing leading bytes
+    /// as zero, matching `From<&[u8]>`.
+    pub fn from_big_endian(data: &[u8]) -> U256 {
+        U256::from(data)
+    }
+
+    /// Parses a fixed-width little-endian byte order encoding.
+    ///
+    /// Accepts slices shorter than 32 bytes, treating missing trailing bytes
+    /// as zero.
+    pub fn from_little_endian(data: &[u8]) -> U256 {
+        assert!(data.len() <= 256 / 8);
+        let mut u256 = U256::zero();
+
+        for i in 0..data.len() {
+            let pos = i / 8;
+            u256.0[pos] += (data[i] as u64) << ((i % 8) * 8);
+        }
+
+        u256
+    }
+
+    /// Writes the lower-case hex encoding (without a `0x` prefix) into `out`,
+    /// avoiding any heap allocation.
+    pub fn to_hex(&self, out: &mut [u8; 64]) {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+        for (i, byte) in self.to_big_endian().iter().enumerate() {
+            out[i * 2] = DIGITS[(byte >> 4) as usize];
+            out[i * 2 + 1] = DIGITS[(byte & 0xf) as usize];
+        }
+    }
+
+    /// Parses a hex string (with or without a leading `0x`) into a `U256`.
+    ///
+    /// Accepts fewer than 64 hex digits, treating missing leading digits as
+    /// zero, same as `from_big_endian`.
+    pub fn from_hex(s: &str) -> Result<U256, ParseError> {
+        let s = if s.starts_with("0x") || s.starts_with("0X") { &s[2..] } else { s };
+        let digits = s.as_bytes();
+        if digits.len() > 64 {
+            return Err(ParseError::InvalidLength);
+        }
+
+        let mut bytes = [0u8; 32];
+        let mut byte_index = 32;
+        let mut i = digits.len();
+        while i > 0 {
+            let lo = hex_digit(digits[i - 1])?;
+            let hi = if i >= 2 { hex_digit(digits[i - 2])? } else { 0 };
+            byte_index -= 1;
+            bytes[byte_index] = (hi << 4) | lo;
+            i = if i >= 2 { i - 2 } else { i - 1 };
+        }
+
+        Ok(U256::from_big_endian(&bytes))
+    }
+}
+
+fn hex_digit(c: u8) -> Result<u8, ParseError> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(ParseError::InvalidCharacter),
+    }
+}
+
 impl Not for U256 {
     type Output = U256;
 
@@ -120,23 +206,132 @@ impl Not for U256 {
     }
 }
 
-impl Add for U256 {
-    type Output = U256;
-
-    fn add(self, other: U256) -> U256 {
+impl U256 {
+    /// Adds two `U256`s, returning the result along with a boolean indicating
+    /// whether an arithmetic overflow occurred.
+    pub fn overflowing_add(self, other: U256) -> (U256, bool) {
         let U256(ref me) = self;
         let U256(ref you) = other;
 
         let mut ret = [0u64; 4];
         let mut carry = false;
         for i in 0..4 {
-            let (v, o) = me[i].overflowing_add(you[i]);
-            ret[i] = v + (if carry { 1 } else { 0 });
-            carry = o;
+            let (v, o1) = me[i].overflowing_add(you[i]);
+            let (v, o2) = v.overflowing_add(if carry { 1 } else { 0 });
+            ret[i] = v;
+            carry = o1 || o2;
         }
 
-        assert!(carry == false);
-        U256(ret)
+        (U256(ret), carry)
+    }
+
+    /// Adds two `U256`s, wrapping around at the boundary of the type on overflow.
+    pub fn wrapping_add(self, other: U256) -> U256 {
+        self.overflowing_add(other).0
+    }
+
+    /// Adds two `U256`s, returning `None` if an overflow occurred.
+    pub fn checked_add(self, other: U256) -> Option<U256> {
+        match self.overflowing_add(other) {
+            (v, false) => Some(v),
+            (_, true) => None,
+        }
+    }
+
+    /// Subtracts two `U256`s, returning the result along with a boolean
+    /// indicating whether an arithmetic underflow occurred.
+    pub fn overflowing_sub(self, other: U256) -> (U256, bool) {
+        let U256(ref me) = self;
+        let U256(ref you) = other;
+
+        let mut ret = [0u64; 4];
+        let mut borrow = false;
+        for i in 0..4 {
+            let (v, b1) = me[i].overflowing_sub(you[i]);
+            let (v, b2) = v.overflowing_sub(if borrow { 1 } else { 0 });
+            ret[i] = v;
+            borrow = b1 || b2;
+        }
+
+        (U256(ret), borrow)
+    }
+
+    /// Subtracts two `U256`s, wrapping around at the boundary of the type on
+    /// underflow.
+    pub fn wrapping_sub(self, other: U256) -> U256 {
+        self.overflowing_sub(other).0
+    }
+
+    /// Subtracts two `U256`s, returning `None` if an underflow occurred.
+    pub fn checked_sub(self, other: U256) -> Option<U256> {
+        match self.overflowing_sub(other) {
+            (v, false) => Some(v),
+            (_, true) => None,
+        }
+    }
+
+    /// Multiplies two `U256`s together over twice the width, returning the
+    /// eight resulting limbs from least to most significant.
+    fn mul_wide(self, other: U256) -> [u64; 8] {
+        let U256(ref me) = self;
+        let U256(ref you) = other;
+
+        let mut ret = [0u64; 8];
+        for i in 0..4 {
+            let mut carry = 0u128;
+            for j in 0..4 {
+                let prod = me[i] as u128 * you[j] as u128
+                    + ret[i + j] as u128
+                    + carry;
+                ret[i + j] = prod as u64;
+                carry = prod >> 64;
+            }
+            ret[i + 4] = carry as u64;
+        }
+        ret
+    }
+
+    /// Multiplies two `U256`s, returning the result along with a boolean
+    /// indicating whether an arithmetic overflow occurred.
+    pub fn overflowing_mul(self, other: U256) -> (U256, bool) {
+        let wide = self.mul_wide(other);
+        let mut ret = [0u64; 4];
+        ret.copy_from_slice(&wide[0..4]);
+        let overflow = wide[4..8].iter().any(|&limb| limb != 0);
+        (U256(ret), overflow)
+    }
+
+    /// Multiplies two `U256`s, wrapping around at the boundary of the type on
+    /// overflow.
+    pub fn wrapping_mul(self, other: U256) -> U256 {
+        self.overflowing_mul(other).0
+    }
+
+    /// Multiplies two `U256`s, returning `None` if an overflow occurred.
+    pub fn checked_mul(self, other: U256) -> Option<U256> {
+        match self.overflowing_mul(other) {
+            (v, false) => Some(v),
+            (_, true) => None,
+        }
+    }
+
+    /// Divides two `U256`s, returning `None` if the divisor is zero.
+    pub fn checked_div(self, other: U256) -> Option<U256> {
+        if other == U256::zero() {
+            None
+        } else {
+            Some(self / other)
+        }
+    }
+}
+
+impl Add for U256 {
+    type Output = U256;
+
+    fn add(self, other: U256) -> U256 {
+        let (ret, overflow) = self.overflowing_add(other);
+        assert!(!overflow, "arithmetic operation overflowed");
+        ret
     }
 }
 
@@ -145,7 +340,9 @@ impl Sub for U256 {
 
     #[inline]
     fn sub(self, other: U256) -> U256 {
-        self + !other
+        let (ret, underflow) = self.overflowing_sub(other);
+        assert!(!underflow, "arithmetic operation overflowed");
+        ret
     }
 }
 
@@ -153,19 +350,14 @@ impl Mul for U256 {
     type Output = U256;
 
     fn mul(self, other: U256) -> U256 {
-        let mut me = self;
-        // TODO: be more efficient about this
-        for i in 0..(2 * 4) {
-            me = (me + me.mul_u32((other >> (32 * i)).low_u32())) << (32 * i);
-        }
-        me
+        self.wrapping_mul(other)
     }
 }
 
-impl Div for U256 {
-    type Output = U256;
-
-    fn div(self, other: U256) -> U256 {
+impl U256 {
+    /// Divides `self` by `other`, returning the quotient and remainder
+    /// together so callers needing both don't pay for the long division twice.
+    pub fn div_rem(self, other: U256) -> (U256, U256) {
         let mut sub_copy = self;
         let mut shift_copy = other;
         let mut ret = [0u64; 4];
@@ -178,7 +370,7 @@ impl Div for U256 {
 
         // Early return in case we are dividing by a larger number than us
         if my_bits < your_bits {
-            return U256(ret);
+            return (U256(ret), sub_copy);
         }
 
         // Bitwise long division
@@ -194,7 +386,23 @@ impl Div for U256 {
             shift -= 1;
         }
 
-        U256(ret)
+        (U256(ret), sub_copy)
+    }
+}
+
+impl Div for U256 {
+    type Output = U256;
+
+    fn div(self, other: U256) -> U256 {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem for U256 {
+    type Output = U256;
+
+    fn rem(self, other: U256) -> U256 {
+        self.div_rem(other).1
     }
 }
 
@@ -218,6 +426,65 @@ impl PartialOrd for U256 {
     }
 }
 
+/// A constant-time boolean: `1` for true, `0` for false. Carrying the result
+/// of a secret-dependent comparison as a `Choice` instead of a `bool` keeps
+/// callers from being tempted to branch on it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Choice(u8);
+
+impl Choice {
+    pub fn unwrap_u8(&self) -> u8 { self.0 }
+}
+
+impl From<bool> for Choice {
+    fn from(val: bool) -> Choice { Choice(val as u8) }
+}
+
+impl From<Choice> for bool {
+    fn from(choice: Choice) -> bool { choice.0 != 0 }
+}
+
+impl U256 {
+    /// Constant-time equality: folds all four limbs with XOR/OR instead of
+    /// `Ord`'s early-returning comparison, so the result doesn't leak which
+    /// limb (if any) the two values first differ in.
+    pub fn ct_eq(&self, other: &U256) -> Choice {
+        let &U256(ref me) = self;
+        let &U256(ref you) = other;
+        let mut diff = 0u64;
+        for i in 0..4 {
+            diff |= me[i] ^ you[i];
+        }
+        Choice::from(diff == 0)
+    }
+
+    /// Constant-time `self > other`, built on `overflowing_sub`'s unconditional
+    /// limb-wise borrow propagation rather than a short-circuiting comparison.
+    pub fn ct_gt(&self, other: &U256) -> Choice {
+        let (U256(diff), borrow) = self.overflowing_sub(*other);
+        let is_zero = diff.iter().fold(0u64, |acc, &limb| acc | limb) == 0;
+        Choice::from(!borrow && !is_zero)
+    }
+
+    /// Constant-time `self < other`.
+    pub fn ct_lt(&self, other: &U256) -> Choice {
+        other.ct_gt(self)
+    }
+
+    /// Returns `a` if `choice` is false, `b` if `choice` is true, using
+    /// bitmask arithmetic instead of a conditional branch.
+    pub fn conditional_select(a: &U256, b: &U256, choice: Choice) -> U256 {
+        let &U256(ref a) = a;
+        let &U256(ref b) = b;
+        let mask = 0u64.wrapping_sub(choice.unwrap_u8() as u64);
+        let mut ret = [0u64; 4];
+        for i in 0..4 {
+            ret[i] = (a[i] & !mask) | (b[i] & mask);
+        }
+        U256(ret)
+    }
+}
+
 impl Shl<usize> for U256 {
     type Output = U256;
 
@@ -260,9 +527,212 @@ impl Shr<usize> for U256 {
     }
 }
 
+impl U256 {
+    /// Decodes a Bitcoin-style compact ("nBits") proof-of-work target.
+    ///
+    /// The high byte of `bits` is an exponent and the low 24 bits are the
+    /// mantissa. The sign bit (`0x00800000`) is invalid for these unsigned
+    /// targets and decodes to `U256::zero()`.
+    pub fn from_compact(bits: u32) -> U256 {
+        if bits & 0x00800000 != 0 {
+            return U256::zero();
+        }
+
+        let exponent = (bits >> 24) as usize;
+        let mantissa = U256([(bits & 0x007fffff) as u64, 0, 0, 0]);
+
+        if exponent <= 3 {
+            mantissa >> (8 * (3 - exponent))
+        } else {
+            mantissa << (8 * (exponent - 3))
+        }
+    }
+
+    /// Encodes `self` as a Bitcoin-style compact ("nBits") proof-of-work target.
+    pub fn to_compact(&self) -> u32 {
+        let mut size = (self.bits() + 7) / 8;
+        let mut compact = if size <= 3 {
+            self.low_u32() << (8 * (3 - size))
+        } else {
+            (*self >> (8 * (size - 3))).low_u32()
+        };
+
+        // 0x00800000 is reserved as a sign bit; shift away from it rather
+        // than let it get interpreted as one.
+        if compact & 0x00800000 != 0 {
+            compact >>= 8;
+            size += 1;
+        }
+
+        compact | ((size as u32) << 24)
+    }
+
+    /// A coarse `bits()`-derived proxy for proof-of-work difficulty: the
+    /// number of leading zero bits in the target relative to the full
+    /// 256-bit range. Smaller targets (harder proof-of-work) yield larger
+    /// values, letting block-header logic compare targets without
+    /// reimplementing the compact encoding elsewhere.
+    pub fn difficulty_bits(&self) -> usize {
+        256 - self.bits()
+    }
+
+    /// Multiplies two `U256`s together over the full 512-bit result, with no
+    /// truncation or overflow.
+    pub fn mul_full(self, other: U256) -> U512 {
+        U512(self.mul_wide(other))
+    }
+
+    /// Adds two `U256`s modulo `modulo`. `self` and `other` need not already
+    /// be reduced.
+    pub fn add_mod(self, other: U256, modulo: U256) -> U256 {
+        // The true sum is `carry * 2**256 + sum`; represent it exactly as a
+        // `U512` and reduce via the full-width division, same as `mul_mod`.
+        let (sum, overflow) = self.overflowing_add(other);
+        let U256(ref limbs) = sum;
+        let carry = if overflow { 1 } else { 0 };
+        let wide = U512([limbs[0], limbs[1], limbs[2], limbs[3], carry, 0, 0, 0]);
+        wide.divrem(&modulo).1
+    }
+
+    /// Multiplies two `U256`s modulo `modulo`.
+    pub fn mul_mod(self, other: U256, modulo: U256) -> U256 {
+        self.mul_full(other).divrem(&modulo).1
+    }
+
+    /// Raises `self` to the power `exp` modulo `modulo`, via square-and-multiply.
+    pub fn pow_mod(self, exp: U256, modulo: U256) -> U256 {
+        let U256(ref exp_limbs) = exp;
+        let mut result = U256([1, 0, 0, 0]).mul_mod(U256([1, 0, 0, 0]), modulo);
+        let mut base = self.mul_mod(U256([1, 0, 0, 0]), modulo);
+        for limb in 0..4 {
+            for bit in 0..64 {
+                if exp_limbs[limb] & (1 << bit) != 0 {
+                    result = result.mul_mod(base, modulo);
+                }
+                base = base.mul_mod(base, modulo);
+            }
+        }
+        result
+    }
+}
+
+/// A 512-bit unsigned integer, used as the double-width result of multiplying
+/// two `U256`s together.
+#[repr(C)]
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct U512([u64; 8]);
+
+impl U512 {
+    pub fn zero() -> U512 { U512([0; 8]) }
+
+    fn bit(&self, index: usize) -> bool {
+        let &U512(ref arr) = self;
+        arr[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Divides `self` by `modulo`, returning `(quotient, remainder)`.
+    ///
+    /// The quotient is returned as a `U256`, so it only holds the full
+    /// mathematical quotient when `self < modulo * 2**256`; otherwise the
+    /// high bits are silently dropped. The remainder is always exact
+    /// regardless. `add_mod`/`mul_mod` rely on this: with a small `modulo`
+    /// and large unreduced operands the quotient routinely doesn't fit, but
+    /// they only ever use the remainder.
+    pub fn divrem(&self, modulo: &U256) -> (U256, U256) {
+        assert!(modulo.bits() != 0, "division by zero");
+        let wide_modulo = U512::from(*modulo);
+        let mut quotient = [0u64; 4];
+        let mut remainder = U512::zero();
+
+        let mut i = 512;
+        while i > 0 {
+            i -= 1;
+            remainder = remainder << 1;
+            if self.bit(i) {
+                remainder.0[0] |= 1;
+            }
+            if remainder >= wide_modulo {
+                remainder = remainder - wide_modulo;
+                if i < 256 {
+                    quotient[i / 64] |= 1 << (i % 64);
+                }
+            }
+        }
+
+        (U256(quotient), U256([remainder.0[0], remainder.0[1], remainder.0[2], remainder.0[3]]))
+    }
+}
+
+impl From<U256> for U512 {
+    fn from(val: U256) -> U512 {
+        let U256(ref arr) = val;
+        U512([arr[0], arr[1], arr[2], arr[3], 0, 0, 0, 0])
+    }
+}
+
+impl Ord for U512 {
+    fn cmp(&self, other: &U512) -> Ordering {
+        let &U512(ref me) = self;
+        let &U512(ref you) = other;
+        let mut i = 8;
+        while i > 0 {
+            i -= 1;
+            if me[i] < you[i] { return Ordering::Less; }
+            if me[i] > you[i] { return Ordering::Greater; }
+        }
+        Ordering::Equal
+    }
+}
+
+impl PartialOrd for U512 {
+    fn partial_cmp(&self, other: &U512) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Sub for U512 {
+    type Output = U512;
+
+    fn sub(self, other: U512) -> U512 {
+        let U512(ref me) = self;
+        let U512(ref you) = other;
+
+        let mut ret = [0u64; 8];
+        let mut borrow = false;
+        for i in 0..8 {
+            let (v, b1) = me[i].overflowing_sub(you[i]);
+            let (v, b2) = v.overflowing_sub(if borrow { 1 } else { 0 });
+            ret[i] = v;
+            borrow = b1 || b2;
+        }
+
+        U512(ret)
+    }
+}
+
+impl Shl<usize> for U512 {
+    type Output = U512;
+
+    fn shl(self, shift: usize) -> U512 {
+        let U512(ref original) = self;
+        let mut ret = [0u64; 8];
+        let word_shift = shift / 64;
+        let bit_shift = shift % 64;
+        for i in 0..8 {
+            if bit_shift < 64 && i + word_shift < 8 {
+                ret[i + word_shift] += original[i] << bit_shift;
+            }
+            if bit_shift > 0 && i + word_shift + 1 < 8 {
+                ret[i + word_shift + 1] += original[i] >> (64 - bit_shift);
+            }
+        }
+        U512(ret)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use U256;
+    use {Choice, ParseError, U256, U512};
 
     #[test]
     fn u256_add() {
@@ -272,4 +742,223 @@ mod tests {
             U256([0xfffffffffffffffeu64, 1u64, 0u64, 0u64])
         );
     }
+
+    #[test]
+    fn u256_mul() {
+        let a = U256([0x100000000u64, 0, 0, 0]);
+        let b = U256([0x100000000u64, 0, 0, 0]);
+        assert_eq!(a * b, U256([0, 1, 0, 0]));
+    }
+
+    #[test]
+    fn u256_overflowing_add() {
+        let max = U256([0xffffffffffffffffu64; 4]);
+        let one = U256([1u64, 0, 0, 0]);
+        assert_eq!(max.overflowing_add(one), (U256::zero(), true));
+        assert_eq!(one.overflowing_add(one), (U256([2u64, 0, 0, 0]), false));
+    }
+
+    #[test]
+    fn u256_checked_sub_underflow() {
+        let one = U256([1u64, 0, 0, 0]);
+        let two = U256([2u64, 0, 0, 0]);
+        assert_eq!(U256::zero().checked_sub(one), None);
+        assert_eq!(two.checked_sub(one), Some(one));
+    }
+
+    #[test]
+    fn u256_checked_mul_overflow() {
+        let max = U256([0xffffffffffffffffu64; 4]);
+        let two = U256([2u64, 0, 0, 0]);
+        let three = U256([3u64, 0, 0, 0]);
+        let four = U256([4u64, 0, 0, 0]);
+        let twelve = U256([12u64, 0, 0, 0]);
+        assert_eq!(max.checked_mul(two), None);
+        assert_eq!(three.checked_mul(four), Some(twelve));
+    }
+
+    #[test]
+    fn u256_checked_div_by_zero() {
+        let ten = U256([10u64, 0, 0, 0]);
+        let two = U256([2u64, 0, 0, 0]);
+        let five = U256([5u64, 0, 0, 0]);
+        assert_eq!(ten.checked_div(U256::zero()), None);
+        assert_eq!(ten.checked_div(two), Some(five));
+    }
+
+    #[test]
+    fn u256_div_rem() {
+        let ten = U256([10u64, 0, 0, 0]);
+        let three = U256([3u64, 0, 0, 0]);
+        assert_eq!(ten.div_rem(three), (U256([3u64, 0, 0, 0]), U256([1u64, 0, 0, 0])));
+        assert_eq!(ten / three, U256([3u64, 0, 0, 0]));
+        assert_eq!(ten % three, U256([1u64, 0, 0, 0]));
+    }
+
+    #[test]
+    fn u256_big_endian_roundtrip() {
+        let n = U256([0x1122334455667788, 0, 0, 0x8877665544332211]);
+        let bytes = n.to_big_endian();
+        assert_eq!(&bytes[0..8], &[0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11]);
+        assert_eq!(U256::from_big_endian(&bytes), n);
+    }
+
+    #[test]
+    fn u256_little_endian_roundtrip() {
+        let n = U256([0x1122334455667788, 0, 0, 0x8877665544332211]);
+        let bytes = n.to_little_endian();
+        assert_eq!(&bytes[0..8], &[0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11]);
+        assert_eq!(U256::from_little_endian(&bytes), n);
+    }
+
+    #[test]
+    fn u256_hex_roundtrip() {
+        let n = U256([0xdeadbeefu64, 0, 0, 0]);
+        let mut buf = [0u8; 64];
+        n.to_hex(&mut buf);
+        let hex = core::str::from_utf8(&buf).unwrap();
+        assert_eq!(
+            hex,
+            "00000000000000000000000000000000000000000000000000000000deadbeef"
+        );
+        assert_eq!(U256::from_hex(hex), Ok(n));
+        assert_eq!(U256::from_hex("0xdeadbeef"), Ok(n));
+    }
+
+    #[test]
+    fn u256_from_hex_errors() {
+        assert_eq!(U256::from_hex("zz"), Err(ParseError::InvalidCharacter));
+        let too_long = "0".repeat(65);
+        assert_eq!(U256::from_hex(&too_long), Err(ParseError::InvalidLength));
+    }
+
+    #[test]
+    fn u256_compact_genesis_target() {
+        // Bitcoin mainnet genesis block target, bits = 0x1d00ffff.
+        let target = U256::from_compact(0x1d00ffff);
+        let expected = U256::from_hex(
+            "00000000ffff0000000000000000000000000000000000000000000000000000"
+        ).unwrap();
+        assert_eq!(target, expected);
+        assert_eq!(target.to_compact(), 0x1d00ffff);
+    }
+
+    #[test]
+    fn u256_compact_roundtrip_small() {
+        let n = U256([0x00123456, 0, 0, 0]);
+        let bits = n.to_compact();
+        assert_eq!(U256::from_compact(bits), n);
+    }
+
+    #[test]
+    fn u256_compact_rejects_sign_bit() {
+        assert_eq!(U256::from_compact(0x01800000), U256::zero());
+    }
+
+    #[test]
+    fn u256_ct_eq() {
+        let a = U256([1u64, 2, 3, 4]);
+        let b = U256([1u64, 2, 3, 4]);
+        let c = U256([1u64, 2, 3, 5]);
+        assert!(bool::from(a.ct_eq(&b)));
+        assert!(!bool::from(a.ct_eq(&c)));
+    }
+
+    #[test]
+    fn u256_ct_gt_lt() {
+        let small = U256([1u64, 0, 0, 0]);
+        let big = U256([2u64, 0, 0, 0]);
+        assert!(bool::from(big.ct_gt(&small)));
+        assert!(!bool::from(small.ct_gt(&big)));
+        assert!(!bool::from(small.ct_gt(&small)));
+        assert!(bool::from(small.ct_lt(&big)));
+        assert!(!bool::from(big.ct_lt(&small)));
+    }
+
+    #[test]
+    fn u256_conditional_select() {
+        let a = U256([1u64, 0, 0, 0]);
+        let b = U256([2u64, 0, 0, 0]);
+        assert_eq!(U256::conditional_select(&a, &b, Choice::from(false)), a);
+        assert_eq!(U256::conditional_select(&a, &b, Choice::from(true)), b);
+    }
+
+    #[test]
+    fn u256_mul_full() {
+        let max = U256([0xffffffffffffffffu64; 4]);
+        let two = U256([2u64, 0, 0, 0]);
+        let U512(ref limbs) = max.mul_full(two);
+        assert_eq!(
+            limbs,
+            &[0xfffffffffffffffe, 0xffffffffffffffff, 0xffffffffffffffff, 0xffffffffffffffff, 1, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn u512_divrem() {
+        let modulo = U256([13u64, 0, 0, 0]);
+        let wide = U512([100u64, 0, 0, 0, 0, 0, 0, 0]);
+        let (q, r) = wide.divrem(&modulo);
+        assert_eq!(q, U256([7u64, 0, 0, 0]));
+        assert_eq!(r, U256([9u64, 0, 0, 0]));
+    }
+
+    #[test]
+    fn u256_add_mod() {
+        let modulo = U256([13u64, 0, 0, 0]);
+        let a = U256([10u64, 0, 0, 0]);
+        let b = U256([9u64, 0, 0, 0]);
+        assert_eq!(a.add_mod(b, modulo), U256([6u64, 0, 0, 0]));
+    }
+
+    #[test]
+    fn u256_add_mod_unreduced_inputs() {
+        // `self` is already >= `modulo`, so a single subtraction isn't enough.
+        let modulo = U256([1u64, 0, 0, 0]);
+        let a = U256([2u64, 0, 0, 0]);
+        assert_eq!(a.add_mod(U256::zero(), modulo), U256::zero());
+    }
+
+    #[test]
+    fn u256_add_mod_overflowing_sum_modulo_one() {
+        // The widened sum is exactly `modulo * 2**256`; the quotient doesn't
+        // fit in a `U256`, but `add_mod` only needs the (zero) remainder.
+        let max = U256([0xffffffffffffffffu64; 4]);
+        let one = U256([1u64, 0, 0, 0]);
+        assert_eq!(max.add_mod(one, one), U256::zero());
+    }
+
+    #[test]
+    fn u256_mul_mod_large_quotient() {
+        // The product is far larger than `modulo * 2**256`, so the quotient
+        // is many times wider than a `U256`; only the remainder matters.
+        let max = U256([0xffffffffffffffffu64; 4]);
+        let two = U256([2u64, 0, 0, 0]);
+        // max is odd, so max*max mod 2 == 1.
+        assert_eq!(max.mul_mod(max, two), U256([1u64, 0, 0, 0]));
+    }
+
+    #[test]
+    fn u256_mul_mod() {
+        let modulo = U256([13u64, 0, 0, 0]);
+        let a = U256([10u64, 0, 0, 0]);
+        let b = U256([9u64, 0, 0, 0]);
+        assert_eq!(a.mul_mod(b, modulo), U256([12u64, 0, 0, 0]));
+    }
+
+    #[test]
+    fn u256_pow_mod() {
+        let modulo = U256([13u64, 0, 0, 0]);
+        let base = U256([4u64, 0, 0, 0]);
+        let exp = U256([3u64, 0, 0, 0]);
+        // 4**3 mod 13 == 64 mod 13 == 12
+        assert_eq!(base.pow_mod(exp, modulo), U256([12u64, 0, 0, 0]));
+    }
+
+    #[test]
+    fn u256_pow_mod_modulo_one() {
+        let modulo = U256([1u64, 0, 0, 0]);
+        let base = U256([5u64, 0, 0, 0]);
+        assert_eq!(base.pow_mod(U256::zero(), modulo), U256::zero());
+    }
 }